@@ -0,0 +1,127 @@
+//! On-disk gist cache so the client can browse and draft edits offline,
+//! plus the ETag bookkeeping `update_gist` needs to detect conflicts.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use super::commands::Gist;
+
+const CACHE_FILE_NAME: &str = "gists_cache.json";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CachedGist {
+    pub gist: Gist,
+    pub etag: Option<String>,
+}
+
+/// In-memory cache backed by a JSON file under the app data directory.
+pub struct CacheStore {
+    path: PathBuf,
+    entries: Mutex<HashMap<String, CachedGist>>,
+}
+
+impl CacheStore {
+    /// Creates the app data directory if missing and loads any existing
+    /// cache file from disk.
+    pub fn load(app: &AppHandle) -> Result<Self, String> {
+        let app_data_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+        fs::create_dir_all(&app_data_dir).map_err(|e| e.to_string())?;
+        let path = app_data_dir.join(CACHE_FILE_NAME);
+
+        let entries = if path.exists() {
+            let raw = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+            serde_json::from_str(&raw).unwrap_or_default()
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self {
+            path,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    pub fn get(&self, gist_id: &str) -> Option<CachedGist> {
+        self.entries.lock().unwrap().get(gist_id).cloned()
+    }
+
+    pub fn list(&self) -> Vec<Gist> {
+        self.entries.lock().unwrap().values().map(|c| c.gist.clone()).collect()
+    }
+
+    pub fn insert(&self, gist_id: String, cached: CachedGist) {
+        self.entries.lock().unwrap().insert(gist_id, cached);
+    }
+
+    pub fn persist(&self) -> Result<(), String> {
+        let entries = self.entries.lock().unwrap();
+        let raw = serde_json::to_vec_pretty(&*entries).map_err(|e| e.to_string())?;
+        fs::write(&self.path, raw).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Serialize)]
+pub struct SyncSummary {
+    pub synced: usize,
+}
+
+#[tauri::command]
+pub async fn sync_now(
+    app: AppHandle,
+    auth: tauri::State<'_, super::oauth::AuthState>,
+    cache: tauri::State<'_, CacheStore>,
+) -> Result<SyncSummary, String> {
+    let _ = &app;
+    let token = auth.0.lock().unwrap().clone().ok_or("not logged in")?;
+
+    let client = reqwest::Client::new();
+    let ids: Vec<Gist> = client
+        .get("https://api.github.com/gists")
+        .header(reqwest::header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(reqwest::header::USER_AGENT, "gist-client")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut synced = 0;
+    for summary in ids {
+        let cached = cache.get(&summary.id);
+        let mut request = client
+            .get(format!("https://api.github.com/gists/{}", summary.id))
+            .header(reqwest::header::AUTHORIZATION, format!("Bearer {token}"))
+            .header(reqwest::header::USER_AGENT, "gist-client");
+        if let Some(etag) = cached.as_ref().and_then(|c| c.etag.as_ref()) {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag.as_str());
+        }
+
+        let response = request.send().await.map_err(|e| e.to_string())?;
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            continue;
+        }
+
+        let etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let gist: Gist = response.json().await.map_err(|e| e.to_string())?;
+        cache.insert(gist.id.clone(), CachedGist { gist, etag });
+        synced += 1;
+    }
+    cache.persist()?;
+
+    Ok(SyncSummary { synced })
+}
+
+#[tauri::command]
+pub fn list_cached_gists(cache: tauri::State<'_, CacheStore>) -> Vec<Gist> {
+    cache.list()
+}
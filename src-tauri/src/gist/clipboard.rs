@@ -0,0 +1,91 @@
+//! One-click copy commands for sharing gist content.
+
+use tauri::{AppHandle, State};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use super::commands::Gist;
+use super::oauth::AuthState;
+
+fn token(state: &State<'_, AuthState>) -> Result<String, String> {
+    state.0.lock().unwrap().clone().ok_or("not logged in".to_string())
+}
+
+#[tauri::command]
+pub async fn copy_gist_url(
+    app: AppHandle,
+    state: State<'_, AuthState>,
+    gist_id: String,
+) -> Result<(), String> {
+    let token = token(&state)?;
+    let client = reqwest::Client::new();
+    let gist: Gist = client
+        .get(format!("https://api.github.com/gists/{gist_id}"))
+        .header(reqwest::header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(reqwest::header::USER_AGENT, "gist-client")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    app.clipboard().write_text(gist.html_url).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn copy_gist_raw(
+    app: AppHandle,
+    state: State<'_, AuthState>,
+    gist_id: String,
+) -> Result<(), String> {
+    let token = token(&state)?;
+    let client = reqwest::Client::new();
+    let gist: Gist = client
+        .get(format!("https://api.github.com/gists/{gist_id}"))
+        .header(reqwest::header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(reqwest::header::USER_AGENT, "gist-client")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let raw = gist
+        .files
+        .values()
+        .filter_map(|f| f.content.clone())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    app.clipboard().write_text(raw).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn copy_file_content(
+    app: AppHandle,
+    state: State<'_, AuthState>,
+    gist_id: String,
+    filename: String,
+) -> Result<(), String> {
+    let token = token(&state)?;
+    let client = reqwest::Client::new();
+    let gist: Gist = client
+        .get(format!("https://api.github.com/gists/{gist_id}"))
+        .header(reqwest::header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(reqwest::header::USER_AGENT, "gist-client")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let content = gist
+        .files
+        .get(&filename)
+        .and_then(|f| f.content.clone())
+        .ok_or_else(|| format!("file {filename} not found in gist"))?;
+
+    app.clipboard().write_text(content).map_err(|e| e.to_string())
+}
@@ -0,0 +1,297 @@
+//! Invoke commands wrapping the GitHub Gists REST API.
+//!
+//! Every command pulls the bearer token from [`AuthState`](super::oauth::AuthState)
+//! so the frontend never has to see or forward it.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use super::oauth::AuthState;
+
+const GITHUB_API: &str = "https://api.github.com";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GistFile {
+    pub filename: String,
+    pub content: Option<String>,
+    pub raw_url: Option<String>,
+    pub language: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Gist {
+    pub id: String,
+    pub description: Option<String>,
+    pub public: bool,
+    pub html_url: String,
+    pub files: HashMap<String, GistFile>,
+    pub updated_at: String,
+}
+
+/// Surfaces GitHub's error body instead of letting a non-2xx response fall
+/// through to `.json()` and fail with a confusing deserialization error.
+async fn ensure_success(response: reqwest::Response) -> Result<reqwest::Response, String> {
+    if response.status().is_success() {
+        return Ok(response);
+    }
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    Err(format!("GitHub API error {status}: {body}"))
+}
+
+fn client(state: &State<'_, AuthState>) -> Result<reqwest::Client, String> {
+    let token = state
+        .0
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or("not logged in")?;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        reqwest::header::AUTHORIZATION,
+        format!("Bearer {token}").parse().map_err(|_| "invalid token")?,
+    );
+    headers.insert(reqwest::header::USER_AGENT, "gist-client".parse().unwrap());
+
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_gists(state: State<'_, AuthState>) -> Result<Vec<Gist>, String> {
+    let response = client(&state)?
+        .get(format!("{GITHUB_API}/gists"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    ensure_success(response)
+        .await?
+        .json()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_gist(state: State<'_, AuthState>, gist_id: String) -> Result<Gist, String> {
+    let response = client(&state)?
+        .get(format!("{GITHUB_API}/gists/{gist_id}"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    ensure_success(response)
+        .await?
+        .json()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[derive(Serialize)]
+struct CreateGistBody {
+    description: String,
+    public: bool,
+    files: HashMap<String, GistFileContent>,
+}
+
+#[derive(Serialize)]
+struct GistFileContent {
+    content: String,
+}
+
+#[tauri::command]
+pub async fn create_gist(
+    state: State<'_, AuthState>,
+    description: String,
+    public: bool,
+    files: HashMap<String, String>,
+) -> Result<Gist, String> {
+    let body = CreateGistBody {
+        description,
+        public,
+        files: files
+            .into_iter()
+            .map(|(name, content)| (name, GistFileContent { content }))
+            .collect(),
+    };
+
+    let response = client(&state)?
+        .post(format!("{GITHUB_API}/gists"))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    ensure_success(response)
+        .await?
+        .json()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Outcome of a conflict-aware update: either the push succeeded, or the
+/// gist changed upstream since it was last cached and the frontend needs to
+/// reconcile before retrying.
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum UpdateGistOutcome {
+    Updated(Gist),
+    Conflict { local: Gist, remote: Gist },
+}
+
+#[tauri::command]
+pub async fn update_gist(
+    state: State<'_, AuthState>,
+    cache: State<'_, super::cache::CacheStore>,
+    gist_id: String,
+    description: Option<String>,
+    files: HashMap<String, String>,
+) -> Result<UpdateGistOutcome, String> {
+    let http = client(&state)?;
+    let cached = cache.get(&gist_id);
+
+    // Always check what's upstream before pushing. A conflict can only be
+    // reported for a gist that already has a cached snapshot (from sync_now
+    // or an earlier update_gist); list_gists/get_gist/create_gist do not
+    // fill the cache, so a first edit of an uncached gist is patched as is.
+    let mut request = http.get(format!("{GITHUB_API}/gists/{gist_id}"));
+    if let Some(etag) = cached.as_ref().and_then(|c| c.etag.as_ref()) {
+        request = request.header(reqwest::header::IF_NONE_MATCH, etag.as_str());
+    }
+    let response = request.send().await.map_err(|e| e.to_string())?;
+
+    if response.status() != reqwest::StatusCode::NOT_MODIFIED {
+        let remote: Gist = ensure_success(response)
+            .await?
+            .json()
+            .await
+            .map_err(|e| e.to_string())?;
+        if let Some(cached) = &cached {
+            if cached.gist.updated_at != remote.updated_at {
+                let draft = Gist {
+                    description: description.clone(),
+                    files: merge_draft_files(&cached.gist.files, &files),
+                    ..cached.gist.clone()
+                };
+                return Ok(UpdateGistOutcome::Conflict {
+                    local: draft,
+                    remote,
+                });
+            }
+        } else {
+            // No prior snapshot to diff against, so no conflict is reported.
+            // Seed the cache with this GET; a successful PATCH below
+            // replaces the entry with the updated gist.
+            cache.insert(gist_id.clone(), super::cache::CachedGist { gist: remote, etag: None });
+        }
+    }
+
+    #[derive(Serialize)]
+    struct UpdateGistBody {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        files: HashMap<String, GistFileContent>,
+    }
+
+    let body = UpdateGistBody {
+        description,
+        files: files
+            .into_iter()
+            .map(|(name, content)| (name, GistFileContent { content }))
+            .collect(),
+    };
+
+    let response = http
+        .patch(format!("{GITHUB_API}/gists/{gist_id}"))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let response = ensure_success(response).await?;
+    let etag = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let updated: Gist = response.json().await.map_err(|e| e.to_string())?;
+
+    cache.insert(
+        gist_id,
+        super::cache::CachedGist {
+            gist: updated.clone(),
+            etag,
+        },
+    );
+    cache.persist()?;
+
+    Ok(UpdateGistOutcome::Updated(updated))
+}
+
+/// Layers the caller's pending edits onto the last-known files so a
+/// conflict response reflects the draft the user actually submitted.
+fn merge_draft_files(
+    known: &HashMap<String, GistFile>,
+    draft: &HashMap<String, String>,
+) -> HashMap<String, GistFile> {
+    let mut merged = known.clone();
+    for (filename, content) in draft {
+        merged.insert(
+            filename.clone(),
+            GistFile {
+                filename: filename.clone(),
+                content: Some(content.clone()),
+                raw_url: None,
+                language: None,
+            },
+        );
+    }
+    merged
+}
+
+#[tauri::command]
+pub async fn delete_gist(state: State<'_, AuthState>, gist_id: String) -> Result<(), String> {
+    let response = client(&state)?
+        .delete(format!("{GITHUB_API}/gists/{gist_id}"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("failed to delete gist: {}", response.status()))
+    }
+}
+
+#[tauri::command]
+pub async fn star_gist(state: State<'_, AuthState>, gist_id: String) -> Result<(), String> {
+    let response = client(&state)?
+        .put(format!("{GITHUB_API}/gists/{gist_id}/star"))
+        .header(reqwest::header::CONTENT_LENGTH, 0)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("failed to star gist: {}", response.status()))
+    }
+}
+
+#[tauri::command]
+pub async fn unstar_gist(state: State<'_, AuthState>, gist_id: String) -> Result<(), String> {
+    let response = client(&state)?
+        .delete(format!("{GITHUB_API}/gists/{gist_id}/star"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("failed to unstar gist: {}", response.status()))
+    }
+}
@@ -0,0 +1,42 @@
+//! The `gist` plugin: everything needed to authenticate with GitHub, manage
+//! gists, and notify the user about activity, bundled behind a single
+//! `init()` so `run()` only has to mount one plugin.
+
+mod cache;
+mod clipboard;
+mod commands;
+mod notify;
+mod oauth;
+
+use tauri::plugin::{Builder, TauriPlugin};
+use tauri::{Manager, Wry};
+
+pub fn init() -> TauriPlugin<Wry> {
+    Builder::new("gist")
+        .invoke_handler(tauri::generate_handler![
+            oauth::start_github_login,
+            commands::list_gists,
+            commands::get_gist,
+            commands::create_gist,
+            commands::update_gist,
+            commands::delete_gist,
+            commands::star_gist,
+            commands::unstar_gist,
+            notify::set_polling_interval,
+            notify::set_notifications_enabled,
+            clipboard::copy_gist_url,
+            clipboard::copy_gist_raw,
+            clipboard::copy_file_content,
+            cache::sync_now,
+            cache::list_cached_gists,
+        ])
+        .setup(|app, _api| {
+            app.manage(oauth::AuthState::default());
+            app.manage(notify::PollingState::default());
+            let cache_store = cache::CacheStore::load(app).map_err(|e| e.to_string())?;
+            app.manage(cache_store);
+            notify::spawn_polling_task(app.clone());
+            Ok(())
+        })
+        .build()
+}
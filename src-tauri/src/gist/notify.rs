@@ -0,0 +1,183 @@
+//! Background polling for gist activity, surfaced as OS notifications.
+//!
+//! A task spawned from `.setup()` wakes up on an interval, fetches the
+//! authenticated user's gists and their comments, and diffs them against a
+//! snapshot kept in [`PollingState`]. A new comment or a changed `updated_at`
+//! on a gist already in the snapshot triggers a notification; a gist seen
+//! for the first time only seeds the snapshot.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Deserialize;
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_notification::NotificationExt;
+
+use super::commands::Gist;
+use super::oauth::AuthState;
+
+const GITHUB_API: &str = "https://api.github.com";
+const DEFAULT_INTERVAL_SECS: u64 = 60;
+const MIN_INTERVAL_SECS: u64 = 15;
+
+#[derive(Deserialize)]
+struct Comment {
+    id: u64,
+}
+
+struct GistSnapshot {
+    updated_at: String,
+    comment_ids: Vec<u64>,
+    comments_etag: Option<String>,
+}
+
+/// Polling cadence, opt-out flag, and the last-seen snapshot per gist.
+pub struct PollingState {
+    pub interval_secs: Mutex<u64>,
+    pub enabled: Mutex<bool>,
+    snapshots: Mutex<HashMap<String, GistSnapshot>>,
+}
+
+impl Default for PollingState {
+    fn default() -> Self {
+        Self {
+            interval_secs: Mutex::new(DEFAULT_INTERVAL_SECS),
+            enabled: Mutex::new(true),
+            snapshots: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+/// Spawns the background polling loop. Meant to be called once from
+/// `.setup()`.
+pub fn spawn_polling_task(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let interval = {
+                let state = app.state::<PollingState>();
+                let enabled = *state.enabled.lock().unwrap();
+                let interval = *state.interval_secs.lock().unwrap();
+                if enabled {
+                    poll_once(&app).await;
+                }
+                interval
+            };
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+        }
+    });
+}
+
+async fn poll_once(app: &AppHandle) {
+    let token = {
+        let auth = app.state::<AuthState>();
+        auth.0.lock().unwrap().clone()
+    };
+    let Some(token) = token else { return };
+
+    let client = reqwest::Client::new();
+    let response = match client
+        .get(format!("{GITHUB_API}/gists"))
+        .header(reqwest::header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(reqwest::header::USER_AGENT, "gist-client")
+        .send()
+        .await
+    {
+        Ok(response) => response,
+        Err(_) => return,
+    };
+    let gists: Vec<Gist> = match response.json().await {
+        Ok(gists) => gists,
+        Err(_) => return,
+    };
+
+    let state = app.state::<PollingState>();
+    for gist in gists {
+        let previous_etag = state
+            .snapshots
+            .lock()
+            .unwrap()
+            .get(&gist.id)
+            .and_then(|p| p.comments_etag.clone());
+
+        let mut request = client
+            .get(format!("{GITHUB_API}/gists/{}/comments", gist.id))
+            .header(reqwest::header::AUTHORIZATION, format!("Bearer {token}"))
+            .header(reqwest::header::USER_AGENT, "gist-client");
+        if let Some(etag) = &previous_etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag.as_str());
+        }
+        let comments_response = request.send().await.ok();
+
+        let (is_new_gist, prev_updated_at, prev_comment_ids) = {
+            let snapshots = state.snapshots.lock().unwrap();
+            match snapshots.get(&gist.id) {
+                Some(p) => (false, Some(p.updated_at.clone()), p.comment_ids.clone()),
+                None => (true, None, Vec::new()),
+            }
+        };
+        let gist_updated = prev_updated_at.is_some_and(|prev| prev != gist.updated_at);
+
+        // A 304 means GitHub has nothing new to report and, crucially,
+        // doesn't count against the rate limit — keep the last comment snapshot.
+        let not_modified = comments_response
+            .as_ref()
+            .is_some_and(|r| r.status() == reqwest::StatusCode::NOT_MODIFIED);
+        if not_modified {
+            if !is_new_gist && gist_updated {
+                notify(app, "Gist updated", &format!("Gist {} was updated", gist.id));
+            }
+            if let Some(snapshot) = state.snapshots.lock().unwrap().get_mut(&gist.id) {
+                snapshot.updated_at = gist.updated_at;
+            }
+            continue;
+        }
+
+        let comments_etag = comments_response
+            .as_ref()
+            .and_then(|r| r.headers().get(reqwest::header::ETAG))
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let comments: Vec<Comment> = match comments_response {
+            Some(response) => response.json().await.unwrap_or_default(),
+            None => Vec::new(),
+        };
+
+        let comment_ids: Vec<u64> = comments.iter().map(|c| c.id).collect();
+        let new_comments = !is_new_gist
+            && comment_ids.iter().any(|id| !prev_comment_ids.contains(id));
+
+        if !is_new_gist && new_comments {
+            notify(app, "New comment", &format!("New comment on gist {}", gist.id));
+        } else if !is_new_gist && gist_updated {
+            notify(app, "Gist updated", &format!("Gist {} was updated", gist.id));
+        }
+
+        state.snapshots.lock().unwrap().insert(
+            gist.id.clone(),
+            GistSnapshot {
+                updated_at: gist.updated_at,
+                comment_ids,
+                comments_etag,
+            },
+        );
+    }
+}
+
+fn notify(app: &AppHandle, title: &str, body: &str) {
+    let _ = app.notification().builder().title(title).body(body).show();
+}
+
+#[tauri::command]
+pub fn set_polling_interval(state: State<'_, PollingState>, seconds: u64) -> Result<(), String> {
+    if seconds < MIN_INTERVAL_SECS {
+        return Err(format!("polling interval must be at least {MIN_INTERVAL_SECS}s"));
+    }
+    *state.interval_secs.lock().unwrap() = seconds;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_notifications_enabled(state: State<'_, PollingState>, enabled: bool) {
+    *state.enabled.lock().unwrap() = enabled;
+}
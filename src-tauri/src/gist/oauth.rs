@@ -0,0 +1,153 @@
+//! GitHub OAuth login via a loopback redirect.
+//!
+//! Desktop apps can't receive a redirect to `https://...`, so we spin up a
+//! throwaway HTTP server on `127.0.0.1` with an OS-assigned port, send the
+//! user to GitHub's authorize page with that port baked into the
+//! `redirect_uri`, and wait for the single callback request.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::Mutex;
+
+use rand::Rng;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_opener::OpenerExt;
+
+const GITHUB_CLIENT_ID: &str = env!("GIST_CLIENT_GITHUB_CLIENT_ID");
+const GITHUB_CLIENT_SECRET: &str = env!("GIST_CLIENT_GITHUB_CLIENT_SECRET");
+const AUTHORIZE_URL: &str = "https://github.com/login/oauth/authorize";
+const ACCESS_TOKEN_URL: &str = "https://github.com/login/oauth/access_token";
+
+/// Holds the access token once the OAuth dance completes.
+#[derive(Default)]
+pub struct AuthState(pub Mutex<Option<String>>);
+
+#[derive(Clone, Serialize)]
+struct LoginSucceeded {
+    token: String,
+}
+
+#[derive(Clone, Serialize)]
+struct LoginFailed {
+    message: String,
+}
+
+/// Starts the loopback server, opens the GitHub authorize page, and emits
+/// `github-login-success` or `github-login-error` once the redirect lands.
+#[tauri::command]
+pub async fn start_github_login(app: AppHandle, state: State<'_, AuthState>) -> Result<(), String> {
+    let listener = TcpListener::bind("127.0.0.1:0").map_err(|e| e.to_string())?;
+    let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+    let expected_state = random_state();
+    let redirect_uri = format!("http://127.0.0.1:{port}");
+
+    let authorize_url = format!(
+        "{AUTHORIZE_URL}?client_id={GITHUB_CLIENT_ID}&scope=gist&redirect_uri={redirect_uri}&state={expected_state}",
+        redirect_uri = urlencoding::encode(&redirect_uri),
+    );
+    app.opener()
+        .open_url(authorize_url, None::<&str>)
+        .map_err(|e| e.to_string())?;
+
+    let redirect = tauri::async_runtime::spawn_blocking(move || await_redirect(listener))
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|result| result);
+    let (code, returned_state) = match redirect {
+        Ok(params) => params,
+        Err(message) => {
+            let _ = app.emit("github-login-error", LoginFailed { message: message.clone() });
+            return Err(message);
+        }
+    };
+    if returned_state != expected_state {
+        let message = "state mismatch, possible CSRF".to_string();
+        let _ = app.emit("github-login-error", LoginFailed { message: message.clone() });
+        return Err(message);
+    }
+
+    match exchange_code(&code, &redirect_uri).await {
+        Ok(token) => {
+            *state.0.lock().unwrap() = Some(token.clone());
+            let _ = app.emit("github-login-success", LoginSucceeded { token });
+            Ok(())
+        }
+        Err(message) => {
+            let _ = app.emit("github-login-error", LoginFailed { message: message.clone() });
+            Err(message)
+        }
+    }
+}
+
+/// Blocks on the loopback listener for the single browser redirect and
+/// returns the `code`/`state` query params, then shuts the server down.
+fn await_redirect(listener: TcpListener) -> Result<(String, String), String> {
+    let (mut stream, _) = listener.accept().map_err(|e| e.to_string())?;
+    let mut reader = BufReader::new(stream.try_clone().map_err(|e| e.to_string())?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).map_err(|e| e.to_string())?;
+
+    let path = request_line
+        .split_whitespace()
+        .nth(1)
+        .ok_or("malformed redirect request")?;
+    let query = path.splitn(2, '?').nth(1).unwrap_or("");
+    let params = parse_query(query);
+
+    let body = "<html><body>Login complete, you can close this tab.</body></html>";
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nContent-Type: text/html\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+
+    let code = params.get("code").cloned().ok_or("missing code param")?;
+    let returned_state = params.get("state").cloned().ok_or("missing state param")?;
+    Ok((code, returned_state))
+}
+
+fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.to_string(), urlencoding::decode(v).unwrap_or_default().into_owned()))
+        .collect()
+}
+
+async fn exchange_code(code: &str, redirect_uri: &str) -> Result<String, String> {
+    #[derive(serde::Deserialize)]
+    struct AccessTokenResponse {
+        access_token: Option<String>,
+        error_description: Option<String>,
+    }
+
+    let client = reqwest::Client::new();
+    let response: AccessTokenResponse = client
+        .post(ACCESS_TOKEN_URL)
+        .header("Accept", "application/json")
+        .form(&[
+            ("client_id", GITHUB_CLIENT_ID),
+            ("client_secret", GITHUB_CLIENT_SECRET),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+        ])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match response.access_token {
+        Some(token) => Ok(token),
+        None => Err(response.error_description.unwrap_or_else(|| "token exchange failed".into())),
+    }
+}
+
+fn random_state() -> String {
+    let mut rng = rand::thread_rng();
+    (0..32).map(|_| format!("{:x}", rng.gen_range(0..16))).collect()
+}
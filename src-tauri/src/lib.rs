@@ -1,6 +1,11 @@
+mod gist;
+
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(gist::init())
         .run(tauri::generate_context!())
         .expect("failed to run tauri app");
 }